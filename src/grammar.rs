@@ -0,0 +1,114 @@
+//! PEG grammar for a single line of terminal output. A real Fallout
+//! hacking screen surrounds each word with junk filler characters and
+//! sometimes bracketed token pairs (`<>`, `[]`, `()`, `{}`), and can show
+//! more than one candidate word per line, so a single flat regex capture
+//! no longer cuts it.
+//!
+//! A line is only treated as carrying real entries when it is either a
+//! clean, uniformly-shaped list (all bare words, or all word-count
+//! pairs) or made up of explicitly bracketed tokens. Narration that
+//! mixes bare words with counted ones, or contains stray digits not
+//! attached to any word, is neither, so it is skipped as filler instead
+//! of being parsed into bogus entries or aborting the whole line (e.g.
+//! "ATTEMPTS LEFT: 4" or "3 tries left"). A narration line made entirely
+//! of bare words with no digits at all (e.g. "ENTER PASSWORD NOW") is
+//! indistinguishable from a genuine bare candidate-word list and is
+//! still accepted as one, by design. A likeness count too large to fit
+//! a `usize` is never filler, though: it genuinely fails to parse, and
+//! that failure surfaces through `ParseGuessError.detail` with the
+//! position peg reports.
+
+/// The closing bracket that pairs with `open`, e.g. `<` closes with `>`.
+/// `bracket_open()` only ever matches one of these four characters, so
+/// every other case is unreachable.
+fn matching_close(open: char) -> char {
+    match open {
+        '<' => '>',
+        '[' => ']',
+        '(' => ')',
+        '{' => '}',
+        _ => unreachable!("bracket_open only matches the four recognized openers"),
+    }
+}
+
+peg::parser! {
+    pub(crate) grammar guess_line() for str {
+        /// Anything that isn't a letter, digit, or bracket. Brackets are
+        /// excluded so a bracketed token (`<crate]` included) is never
+        /// absorbed as plain filler around a bare word; it always has to
+        /// go through `bracketed_entry()`'s pairing check instead.
+        rule filler_char() = quiet!{[c if !c.is_alphanumeric() && !"<>[](){}".contains(c)]}
+
+        rule filler() = quiet!{filler_char()*}
+
+        rule word() -> &'input str
+            = w:$(quiet!{[c if c.is_alphabetic()]+}) { w }
+
+        rule count() -> usize
+            = n:$(quiet!{['0'..='9']+}) {? n.parse().or(Err("count")) }
+
+        /// A digit run that doesn't fit in a `usize`. Unlike every other
+        /// malformed shape on this page, an overflowing count is never
+        /// treated as filler: `any_char()` below refuses to step over
+        /// one, so a line containing it can't reach end-of-input and
+        /// the whole parse genuinely fails with a position.
+        rule overflow_count() = n:$(['0'..='9']+) {?
+            match n.parse::<usize>() {
+                Ok(_) => Err("not an overflowing count"),
+                Err(_) => Ok(()),
+            }
+        }
+
+        rule entry() -> (String, Option<usize>)
+            = w:word() filler_char()* c:count()? { (w.to_string(), c) }
+
+        /// Every word on the line, in order, each with the count that
+        /// immediately trails it (if any), provided the whole line is
+        /// nothing but such entries and filler, and every entry has the
+        /// same shape (all bare, or all counted). A mix of bare and
+        /// counted entries is exactly the shape of narration text like
+        /// "ATTEMPTS LEFT: 4", so it is rejected here and left to the
+        /// bracketed fallback below.
+        rule uniform_entries() -> Vec<(String, Option<usize>)>
+            = filler() v:(e:entry() filler() { e })+ ![_] {?
+                if v.iter().all(|(_, c)| c.is_some()) || v.iter().all(|(_, c)| c.is_none()) {
+                    Ok(v)
+                } else {
+                    Err("mixed bare and counted entries")
+                }
+            }
+
+        rule bracket_open() -> char = c:['<' | '[' | '(' | '{'] { c }
+        rule bracket_close() -> char = c:['>' | ']' | ')' | '}'] { c }
+
+        /// A word (and optional count) explicitly set off by one of the
+        /// terminal's bracket pairs, e.g. `<crate>` or `[crate 4]`. These
+        /// are recognized as real entries wherever they appear on a
+        /// line, since bracketing is how the terminal marks a token out
+        /// from the surrounding junk. The closing bracket must match
+        /// the opening one (`<crate]` is not a pair), since otherwise a
+        /// stray closer from one junk token could pair with the opener
+        /// of an unrelated one and fabricate an entry from pure noise.
+        rule bracketed_entry() -> (String, Option<usize>)
+            = open:bracket_open() filler() w:word() (!bracket_close() filler_char())* c:count()? close:bracket_close() {?
+                if close == matching_close(open) {
+                    Ok((w.to_string(), c))
+                } else {
+                    Err("mismatched bracket")
+                }
+            }
+
+        rule any_char() = !overflow_count() [_]
+
+        /// Fallback for lines that are not a clean uniform entry list:
+        /// pick out only the bracketed entries and otherwise ignore the
+        /// rest of the line as junk, rather than failing the whole line.
+        rule bracketed_entries() -> Vec<(String, Option<usize>)>
+            = v:(e:bracketed_entry() { Some(e) } / any_char() { None })* ![_] {
+                v.into_iter().flatten().collect()
+            }
+
+        pub rule entries() -> Vec<(String, Option<usize>)>
+            = uniform_entries() / bracketed_entries()
+    }
+}
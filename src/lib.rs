@@ -0,0 +1,234 @@
+mod grammar;
+pub mod options;
+pub mod solver;
+
+use options::OptionsError;
+use serde::Deserialize;
+use std::{
+    convert::TryFrom,
+    fmt,
+    io::{self, Read},
+    result,
+    vec::Vec,
+};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Guess {
+    pub word: String,
+    pub count: Option<usize>,
+}
+
+impl Guess {
+    pub fn new(word: String) -> Guess {
+        Guess { word, count: None }
+    }
+
+    pub fn new_with_count(word: String, count: usize) -> Guess {
+        Guess {
+            word,
+            count: Some(count),
+        }
+    }
+
+    pub fn num_of_common_letters(&self, other: &Guess) -> usize {
+        self.word
+            .chars()
+            .zip(other.word.chars())
+            .filter(|(letter, other_letter)| letter == other_letter)
+            .count()
+    }
+}
+
+/// Turn one (word, count) pair parsed off a line into a `Guess`,
+/// checking the invariant that a likeness count can never exceed the
+/// length of the word it was reported against.
+fn guess_from_entry(line: &str, word: String, count: Option<usize>) -> Result<Guess> {
+    match count {
+        Some(count) if count > word.chars().count() => Err(ParseGuessError {
+            line: line.into(),
+            detail: "count is longer than the word".into(),
+        }
+        .into()),
+        Some(count) => Ok(Guess::new_with_count(word, count)),
+        None => Ok(Guess::new(word)),
+    }
+}
+
+impl TryFrom<&str> for Guess {
+    type Error = Error;
+
+    fn try_from(line: &str) -> Result<Self> {
+        let entries = grammar::guess_line::entries(line).map_err(|err| ParseGuessError {
+            line: line.into(),
+            detail: err.to_string(),
+        })?;
+
+        let mut entries = entries.into_iter();
+
+        let (word, count) = entries.next().ok_or_else(|| ParseGuessError {
+            line: line.into(),
+            detail: "wrong guess format".into(),
+        })?;
+
+        if entries.next().is_some() {
+            return Err(ParseGuessError {
+                line: line.into(),
+                detail: "expected a single word on this line".into(),
+            }
+            .into());
+        }
+
+        guess_from_entry(line, word, count)
+    }
+}
+
+#[derive(Debug)]
+pub struct ParseGuessError {
+    line: String,
+    detail: String,
+}
+
+impl fmt::Display for ParseGuessError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "cannot parse line \"{}\" into Guess: {}",
+            self.line, self.detail
+        )
+    }
+}
+
+#[derive(Debug)]
+pub struct NoGuessesError {}
+
+impl fmt::Display for NoGuessesError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "no guesses in input",)
+    }
+}
+
+#[derive(Debug)]
+pub struct UnequalLengthsError {}
+
+impl fmt::Display for UnequalLengthsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "guess words have unequal lengths",)
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    ParseGuess(ParseGuessError),
+    IO(io::Error),
+    NoGuesses(NoGuessesError),
+    UnequalLengths(UnequalLengthsError),
+    Options(OptionsError),
+}
+
+impl From<ParseGuessError> for Error {
+    fn from(err: ParseGuessError) -> Self {
+        Self::ParseGuess(err)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Self::IO(err)
+    }
+}
+
+impl From<NoGuessesError> for Error {
+    fn from(err: NoGuessesError) -> Self {
+        Self::NoGuesses(err)
+    }
+}
+
+impl From<UnequalLengthsError> for Error {
+    fn from(err: UnequalLengthsError) -> Self {
+        Self::UnequalLengths(err)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::IO(ref err) => write!(f, "IO error: {}", err),
+            Error::ParseGuess(ref err) => write!(f, "parsing guess error: {}", err),
+            Error::NoGuesses(ref err) => write!(f, "{}", err),
+            Error::UnequalLengths(ref err) => write!(f, "{}", err),
+            Error::Options(ref err) => write!(f, "{}", err),
+        }
+    }
+}
+
+pub type Result<T> = result::Result<T, Error>;
+
+pub fn read_guesses(reader: &mut dyn Read) -> Result<Vec<Guess>> {
+    let mut guesses: Vec<Guess> = Vec::new();
+
+    let mut buffer = String::new();
+    reader.read_to_string(&mut buffer)?;
+
+    for line in buffer.lines() {
+        let entries = grammar::guess_line::entries(line).map_err(|err| ParseGuessError {
+            line: line.into(),
+            detail: err.to_string(),
+        })?;
+
+        if entries.is_empty() {
+            // Not every line carries a guess: terminal narration like
+            // "ATTEMPTS LEFT: 4" parses to no entries and is skipped
+            // rather than aborting the whole read.
+            continue;
+        }
+
+        for (word, count) in entries {
+            guesses.push(guess_from_entry(line, word, count)?);
+        }
+    }
+
+    if guesses.is_empty() {
+        return Err(NoGuessesError {}.into());
+    }
+
+    check_equal_lengths(&guesses)?;
+
+    Ok(guesses)
+}
+
+/// Check the invariant that every guess word has the same length in
+/// characters, so that a likeness count always means the same thing
+/// regardless of which guess it was reported against. Shared by
+/// `read_guesses`'s batch validation and the interactive REPL, which
+/// narrows the same candidate pool one line at a time.
+pub fn check_equal_lengths(guesses: &[Guess]) -> Result<()> {
+    let len = match guesses.first() {
+        Some(guess) => guess.word.chars().count(),
+        None => return Ok(()),
+    };
+
+    for guess in guesses {
+        if guess.word.chars().count() != len {
+            return Err(UnequalLengthsError {}.into());
+        }
+    }
+
+    Ok(())
+}
+
+/// Narrow `candidates` down to the ones consistent with every known
+/// `constraints` entry, i.e. whose likeness count against each
+/// constraint matches the count that constraint carries.
+pub fn filter<'a>(candidates: &'a [Guess], constraints: &[Guess]) -> Vec<&'a Guess> {
+    candidates
+        .iter()
+        .filter(|candidate| {
+            constraints.iter().all(|constraint| {
+                constraint.num_of_common_letters(candidate)
+                    == constraint
+                        .count
+                        .expect("constraints must carry a known count")
+            })
+        })
+        .collect()
+}
@@ -1,238 +1,169 @@
-#[macro_use]
-extern crate lazy_static;
-
-use regex::Regex;
+use fallhack::{
+    check_equal_lengths, filter, options,
+    options::OutputFormat,
+    read_guesses, solver, Guess, NoGuessesError, Result,
+};
 use std::{
-    convert::{TryFrom, TryInto},
-    fmt,
-    io::{self, prelude::*, stdin},
+    convert::TryInto,
+    env, fs,
+    io::{prelude::*, stdin},
     process::exit,
-    result,
     vec::Vec,
 };
 
-#[derive(Debug)]
-struct Guess {
-    word: String,
-    count: Option<usize>,
-}
-
-impl Guess {
-    fn new(word: String) -> Guess {
-        Guess { word, count: None }
-    }
-
-    fn new_with_count(word: String, count: usize) -> Guess {
-        Guess {
-            word,
-            count: Some(count),
-        }
+fn policy_name(policy: solver::Policy) -> &'static str {
+    match policy {
+        solver::Policy::Minimax => "minimax",
+        solver::Policy::ExpectedRemaining => "expected",
+        solver::Policy::Entropy => "entropy",
     }
+}
 
-    fn num_of_common_letters(&self, other: &Guess) -> usize {
-        let mut num: usize = 0;
+fn print_suggestions(filtered_guesses: &[&Guess], policy: solver::Policy) {
+    if filtered_guesses.len() > 1 {
+        let suggestions = solver::recommend(filtered_guesses, policy);
 
-        for (index, letter) in self.word.char_indices() {
-            let other_letter: char = other.word.as_bytes()[index].into();
-            if letter == other_letter {
-                // we've checked that words have equal lengths already
-                num += 1;
-            }
+        println!();
+        println!("top suggestions ({}):", policy_name(policy));
+        for suggestion in suggestions.iter().take(3) {
+            println!("  {} ({:.3})", suggestion.guess.word, suggestion.score);
         }
-
-        num
     }
 }
 
-impl TryFrom<&str> for Guess {
-    type Error = Error;
-
-    fn try_from(line: &str) -> Result<Self> {
-        lazy_static! {
-            static ref GUESS_REGEX: Regex =
-                Regex::new(r"(?P<word>[[:alpha:]]+)[[:space:]]*(?P<count>[[:digit:]]*)")
-                    .expect("could not compile regexp");
-        }
-
-        let captures = match GUESS_REGEX.captures(&line) {
-            None => {
-                return Err(ParseGuessError {
-                    line: line.into(),
-                    detail: "wrong guess format".into(),
-                }
-                .into())
+fn print_filtered_guesses(filtered_guesses: &[&Guess], output: OutputFormat) {
+    match output {
+        OutputFormat::Plain => {
+            for filtered_guess in filtered_guesses {
+                println!("{}", filtered_guess.word);
             }
-            Some(captures) => captures,
-        };
-
-        let word = captures
-            .name("word")
-            .expect("word should have been successfully captured by regex")
-            .as_str();
-
-        let count_str = captures
-            .name("count")
-            .expect("count should have been successfully captured by regex")
-            .as_str();
-
-        if count_str.len() > 0 {
-            let count: usize = count_str
-                .parse()
-                .expect("the regex should not allow this to fail");
-
-            if usize::from(count) > word.len() {
-                return Err(ParseGuessError {
-                    line: line.into(),
-                    detail: "count is longer than the word".into(),
-                }
-                .into());
-            }
-
-            Ok(Guess::new_with_count(word.into(), count))
-        } else {
-            Ok(Guess::new(word.into()))
+        }
+        OutputFormat::Json => {
+            let words: Vec<String> = filtered_guesses
+                .iter()
+                .map(|guess| format!("\"{}\"", guess.word))
+                .collect();
+            println!("[{}]", words.join(", "));
         }
     }
 }
 
-#[derive(Debug)]
-struct ParseGuessError {
-    line: String,
-    detail: String,
-}
-
-impl fmt::Display for ParseGuessError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(
-            f,
-            "cannot parse line \"{}\" into Guess: {}",
-            self.line, self.detail
-        )
-    }
-}
+fn run(options: &options::Options) -> Result<()> {
+    let guesses = match &options.file {
+        Some(path) => read_guesses(&mut fs::File::open(path)?)?,
+        None => read_guesses(&mut stdin())?,
+    };
 
-#[derive(Debug)]
-struct NoGuessesError {}
+    let (constraints, candidates): (Vec<Guess>, Vec<Guess>) =
+        guesses.into_iter().partition(|guess| guess.count.is_some());
 
-impl fmt::Display for NoGuessesError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "no guesses in input",)
-    }
-}
+    let filtered_guesses = filter(&candidates, &constraints);
 
-#[derive(Debug)]
-struct UnequalLengthsError {}
+    print_filtered_guesses(&filtered_guesses, options.output);
 
-impl fmt::Display for UnequalLengthsError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "guess words have unequal lengths",)
+    if options.suggest {
+        print_suggestions(&filtered_guesses, options.policy);
     }
-}
 
-#[derive(Debug)]
-enum Error {
-    ParseGuess(ParseGuessError),
-    IO(io::Error),
-    NoGuesses(NoGuessesError),
-    UnequalLengths(UnequalLengthsError),
+    Ok(())
 }
 
-impl From<ParseGuessError> for Error {
-    fn from(err: ParseGuessError) -> Self {
-        Self::ParseGuess(err)
+fn print_candidates(candidates: &[Guess]) {
+    println!("candidates ({}):", candidates.len());
+    for candidate in candidates {
+        println!("  {}", candidate.word);
     }
 }
 
-impl From<io::Error> for Error {
-    fn from(err: io::Error) -> Self {
-        Self::IO(err)
-    }
-}
+/// Interactively narrow the candidate pool one guess at a time: read the
+/// pool from stdin, then repeatedly prompt for the word just tried and
+/// the likeness count the terminal reported, re-filtering after each one
+/// until a single word remains or the feedback given is contradictory.
+fn run_interactive(options: &options::Options) -> Result<()> {
+    println!("enter candidate words, one per line; blank line to finish:");
 
-impl From<NoGuessesError> for Error {
-    fn from(err: NoGuessesError) -> Self {
-        Self::NoGuesses(err)
-    }
-}
+    let stdin = stdin();
+    let mut lines = stdin.lock().lines();
 
-impl From<UnequalLengthsError> for Error {
-    fn from(err: UnequalLengthsError) -> Self {
-        Self::UnequalLengths(err)
+    let mut candidates: Vec<Guess> = Vec::new();
+    for line in lines.by_ref() {
+        let line = line?;
+        if line.trim().is_empty() {
+            break;
+        }
+        candidates.push(line.trim().try_into()?);
     }
-}
 
-impl fmt::Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {
-            Error::IO(ref err) => write!(f, "IO error: {}", err),
-            Error::ParseGuess(ref err) => write!(f, "parsing guess error: {}", err),
-            Error::NoGuesses(ref err) => write!(f, "{}", err),
-            Error::UnequalLengths(ref err) => write!(f, "{}", err),
-        }
+    if candidates.is_empty() {
+        return Err(NoGuessesError {}.into());
     }
-}
 
-type Result<T> = result::Result<T, Error>;
+    check_equal_lengths(&candidates)?;
+    let word_len = candidates[0].word.chars().count();
 
-fn parse_guesses_from_stdin() -> Result<Vec<Guess>> {
-    let mut guesses: Vec<Guess> = Vec::new();
+    loop {
+        print_candidates(&candidates);
 
-    let mut buffer = String::new();
-    stdin().read_to_string(&mut buffer)?;
+        if candidates.len() <= 1 {
+            break;
+        }
 
-    for line in buffer.lines() {
-        let guess: Guess = line.try_into()?;
-        guesses.push(guess);
-    }
+        println!("enter the word you tried and its likeness count (e.g. \"crate 2\"):");
 
-    if guesses.len() == 0 {
-        return Err(NoGuessesError {}.into());
-    }
+        let line = match lines.next() {
+            Some(line) => line?,
+            None => break,
+        };
 
-    let len = guesses
-        .get(0)
-        .expect("we checked for length above")
-        .word
-        .len();
+        let constraint: Guess = line.trim().try_into()?;
 
-    for guess in guesses.iter() {
-        if guess.word.len() != len {
-            return Err(UnequalLengthsError {}.into());
+        if constraint.count.is_none() {
+            eprintln!("error: expected a word followed by its likeness count");
+            continue;
         }
-    }
 
-    Ok(guesses)
-}
+        if constraint.word.chars().count() != word_len {
+            eprintln!("error: guess words have unequal lengths");
+            continue;
+        }
 
-fn run() -> Result<()> {
-    let guesses = parse_guesses_from_stdin()?;
+        let narrowed: Vec<Guess> = filter(&candidates, &[constraint])
+            .into_iter()
+            .cloned()
+            .collect();
 
-    let guesses_with_count: Vec<&Guess> = guesses
-        .iter()
-        .filter(|guess| guess.count.is_some())
-        .collect();
-    let guesses_without_count = guesses.iter().filter(|guess| guess.count.is_none());
+        if narrowed.is_empty() {
+            eprintln!("error: no candidates remain consistent with that feedback");
+            break;
+        }
 
-    let filtered_guesses = guesses_without_count.filter(|guess_without_count| {
-        guesses_with_count.iter().all(|guess_with_count| {
-            guess_with_count.num_of_common_letters(guess_without_count)
-                == guess_with_count.count.unwrap()
-        })
-    });
+        candidates = narrowed;
+    }
 
-    for filtered_guess in filtered_guesses {
-        println!("{}", filtered_guess.word);
+    if options.suggest {
+        print_suggestions(&candidates.iter().collect::<Vec<_>>(), options.policy);
     }
 
     Ok(())
 }
 
 fn main() {
-    match run() {
-        Err(e) => {
-            eprintln!("error: {}", e);
+    let options = match options::parse(env::args_os().skip(1)) {
+        Ok(options) => options,
+        Err(err) => {
+            eprintln!("error: {}", err);
             exit(1)
         }
-        _ => {}
     };
+
+    let result = if options.interactive {
+        run_interactive(&options)
+    } else {
+        run(&options)
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        exit(1)
+    }
 }
@@ -0,0 +1,125 @@
+use std::{ffi::OsString, fmt, path::PathBuf};
+
+use crate::{solver::Policy, Error};
+
+/// Format used to print the surviving candidate words.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Plain,
+    Json,
+}
+
+/// Parsed command-line options.
+#[derive(Debug)]
+pub struct Options {
+    /// Read guesses from this file instead of stdin.
+    pub file: Option<PathBuf>,
+    /// Narrow the candidate pool one guess at a time instead of reading
+    /// a full batch up front.
+    pub interactive: bool,
+    /// Print the next-guess recommender's top suggestions.
+    pub suggest: bool,
+    /// Format to print the surviving candidates in.
+    pub output: OutputFormat,
+    /// Scoring strategy the next-guess recommender ranks candidates by.
+    pub policy: Policy,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            file: None,
+            interactive: false,
+            suggest: false,
+            output: OutputFormat::Plain,
+            policy: Policy::Entropy,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct OptionsError {
+    detail: String,
+}
+
+impl fmt::Display for OptionsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "cannot parse options: {}", self.detail)
+    }
+}
+
+impl From<OptionsError> for Error {
+    fn from(err: OptionsError) -> Self {
+        Self::Options(err)
+    }
+}
+
+fn unknown(arg: &OsString) -> OptionsError {
+    OptionsError {
+        detail: format!("unknown option \"{}\"", arg.to_string_lossy()),
+    }
+}
+
+fn missing_value(flag: &str) -> OptionsError {
+    OptionsError {
+        detail: format!("option \"{}\" requires a value", flag),
+    }
+}
+
+/// A small hand-rolled parser for the handful of flags this tool needs,
+/// in place of pulling in a heavyweight arg-parsing crate.
+pub fn parse<I: IntoIterator<Item = OsString>>(
+    args: I,
+) -> Result<Options, OptionsError> {
+    let mut options = Options::default();
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.to_str() {
+            Some("-f") | Some("--file") => {
+                let value = args.next().ok_or_else(|| missing_value("-f/--file"))?;
+                options.file = Some(PathBuf::from(value));
+            }
+            Some("-i") | Some("--interactive") => {
+                options.interactive = true;
+            }
+            Some("-s") | Some("--suggest") => {
+                options.suggest = true;
+            }
+            Some("-o") | Some("--output") => {
+                let value = args.next().ok_or_else(|| missing_value("-o/--output"))?;
+                options.output = match value.to_str() {
+                    Some("plain") => OutputFormat::Plain,
+                    Some("json") => OutputFormat::Json,
+                    _ => {
+                        return Err(OptionsError {
+                            detail: format!(
+                                "unknown output format \"{}\" (expected \"plain\" or \"json\")",
+                                value.to_string_lossy()
+                            ),
+                        })
+                    }
+                };
+            }
+            Some("-p") | Some("--policy") => {
+                let value = args.next().ok_or_else(|| missing_value("-p/--policy"))?;
+                options.policy = match value.to_str() {
+                    Some("minimax") => Policy::Minimax,
+                    Some("expected") => Policy::ExpectedRemaining,
+                    Some("entropy") => Policy::Entropy,
+                    _ => {
+                        return Err(OptionsError {
+                            detail: format!(
+                                "unknown policy \"{}\" (expected \"minimax\", \"expected\" or \"entropy\")",
+                                value.to_string_lossy()
+                            ),
+                        })
+                    }
+                };
+            }
+            _ => return Err(unknown(&arg)),
+        }
+    }
+
+    Ok(options)
+}
@@ -0,0 +1,78 @@
+use std::cmp::Ordering;
+
+use crate::Guess;
+
+/// Strategy used to score a candidate guess by how well it is expected
+/// to partition the remaining candidate set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Policy {
+    /// Minimize the size of the largest resulting bucket.
+    Minimax,
+    /// Minimize the expected number of candidates left afterwards,
+    /// i.e. `sum(size_i^2) / total`.
+    ExpectedRemaining,
+    /// Maximize the Shannon entropy of the resulting bucket distribution.
+    Entropy,
+}
+
+/// A candidate guess together with the score it received under some
+/// `Policy`. Higher is always better, regardless of policy.
+pub struct Suggestion<'a> {
+    pub guess: &'a Guess,
+    pub score: f64,
+}
+
+/// Partition `candidates` by the likeness score each would yield against
+/// `guess`, returning the size of every non-empty bucket. The true
+/// password is guaranteed to be in `candidates`, so this enumerates every
+/// reachable outcome of actually trying `guess`.
+fn bucket_sizes(guess: &Guess, candidates: &[&Guess]) -> Vec<usize> {
+    let mut buckets = vec![0usize; guess.word.chars().count() + 1];
+
+    for candidate in candidates {
+        buckets[guess.num_of_common_letters(candidate)] += 1;
+    }
+
+    buckets.into_iter().filter(|&size| size > 0).collect()
+}
+
+fn score(policy: Policy, buckets: &[usize], total: usize) -> f64 {
+    match policy {
+        Policy::Minimax => -(*buckets.iter().max().unwrap_or(&0) as f64),
+        Policy::ExpectedRemaining => {
+            let sum_of_squares: usize = buckets.iter().map(|size| size * size).sum();
+            -(sum_of_squares as f64 / total as f64)
+        }
+        Policy::Entropy => buckets
+            .iter()
+            .map(|&size| {
+                let p = size as f64 / total as f64;
+                -p * p.log2()
+            })
+            .sum(),
+    }
+}
+
+/// Rank every candidate guess by how much it is expected to shrink the
+/// search space under `policy`, most informative first. A guess that
+/// splits the candidates into many small buckets ranks above one that
+/// leaves a single large bucket, since every non-empty bucket is a
+/// reachable outcome and small buckets narrow the search faster.
+pub fn recommend<'a>(candidates: &[&'a Guess], policy: Policy) -> Vec<Suggestion<'a>> {
+    let total = candidates.len();
+
+    let mut suggestions: Vec<Suggestion> = candidates
+        .iter()
+        .map(|&guess| {
+            let buckets = bucket_sizes(guess, candidates);
+            Suggestion {
+                guess,
+                score: score(policy, &buckets, total),
+            }
+        })
+        .collect();
+
+    suggestions.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal));
+
+    suggestions
+}
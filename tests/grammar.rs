@@ -0,0 +1,64 @@
+use std::convert::TryFrom;
+use std::io::Cursor;
+
+use fallhack::{read_guesses, Error, Guess};
+
+#[test]
+fn parses_bare_word_as_candidate() {
+    let guess = Guess::try_from("crate").unwrap();
+    assert_eq!(guess.word, "crate");
+    assert_eq!(guess.count, None);
+}
+
+#[test]
+fn parses_word_and_count_as_constraint() {
+    let guess = Guess::try_from("crate 2").unwrap();
+    assert_eq!(guess.word, "crate");
+    assert_eq!(guess.count, Some(2));
+}
+
+#[test]
+fn parses_bracketed_entry() {
+    let guess = Guess::try_from("<crate 2>").unwrap();
+    assert_eq!(guess.word, "crate");
+    assert_eq!(guess.count, Some(2));
+}
+
+#[test]
+fn rejects_mismatched_bracket_pair() {
+    assert!(Guess::try_from("<crate]").is_err());
+    assert!(Guess::try_from("[crate>").is_err());
+}
+
+#[test]
+fn rejects_count_longer_than_word() {
+    assert!(Guess::try_from("crate 9").is_err());
+}
+
+#[test]
+fn fails_on_count_overflowing_a_usize() {
+    let err = Guess::try_from("crate 99999999999999999999999999999999").unwrap_err();
+    assert!(matches!(err, Error::ParseGuess(_)));
+}
+
+#[test]
+fn read_guesses_skips_narration_lines() {
+    let input = "crate\n3 tries left\nATTEMPTS LEFT: 4\ntrace\n";
+    let guesses = read_guesses(&mut Cursor::new(input)).unwrap();
+    let words: Vec<&str> = guesses.iter().map(|g| g.word.as_str()).collect();
+    assert_eq!(words, vec!["crate", "trace"]);
+}
+
+#[test]
+fn read_guesses_rejects_unequal_word_lengths() {
+    let input = "crate\ntrace\ngrates\n";
+    assert!(read_guesses(&mut Cursor::new(input)).is_err());
+}
+
+#[test]
+fn read_guesses_ignores_mismatched_bracketed_junk() {
+    let input = "junk$$$[happy)junk\ncrate\n";
+    let guesses = read_guesses(&mut Cursor::new(input)).unwrap();
+    let words: Vec<&str> = guesses.iter().map(|g| g.word.as_str()).collect();
+    assert_eq!(words, vec!["crate"]);
+}
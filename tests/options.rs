@@ -0,0 +1,61 @@
+use std::ffi::OsString;
+
+use fallhack::options::{self, OutputFormat};
+use fallhack::solver::Policy;
+
+fn args(words: &[&str]) -> Vec<OsString> {
+    words.iter().map(OsString::from).collect()
+}
+
+#[test]
+fn defaults_to_plain_output_and_entropy_policy() {
+    let options = options::parse(args(&[])).unwrap();
+    assert_eq!(options.output, OutputFormat::Plain);
+    assert_eq!(options.policy, Policy::Entropy);
+    assert!(!options.interactive);
+    assert!(!options.suggest);
+    assert!(options.file.is_none());
+}
+
+#[test]
+fn parses_file_interactive_suggest_and_output_flags() {
+    let options = options::parse(args(&["-f", "puzzle.txt", "-i", "-s", "-o", "json"])).unwrap();
+    assert_eq!(options.file.unwrap().to_str().unwrap(), "puzzle.txt");
+    assert!(options.interactive);
+    assert!(options.suggest);
+    assert_eq!(options.output, OutputFormat::Json);
+}
+
+#[test]
+fn parses_each_policy_flag_value() {
+    let minimax = options::parse(args(&["-p", "minimax"])).unwrap();
+    assert_eq!(minimax.policy, Policy::Minimax);
+
+    let expected = options::parse(args(&["-p", "expected"])).unwrap();
+    assert_eq!(expected.policy, Policy::ExpectedRemaining);
+
+    let entropy = options::parse(args(&["-p", "entropy"])).unwrap();
+    assert_eq!(entropy.policy, Policy::Entropy);
+}
+
+#[test]
+fn rejects_unknown_flag() {
+    assert!(options::parse(args(&["--bogus"])).is_err());
+}
+
+#[test]
+fn rejects_flag_missing_its_value() {
+    assert!(options::parse(args(&["-f"])).is_err());
+    assert!(options::parse(args(&["-o"])).is_err());
+    assert!(options::parse(args(&["-p"])).is_err());
+}
+
+#[test]
+fn rejects_unknown_output_format() {
+    assert!(options::parse(args(&["-o", "xml"])).is_err());
+}
+
+#[test]
+fn rejects_unknown_policy_name() {
+    assert!(options::parse(args(&["-p", "greedy"])).is_err());
+}
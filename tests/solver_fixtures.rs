@@ -0,0 +1,131 @@
+use fallhack::{filter, solver, Guess};
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+struct ConstraintSpec {
+    word: String,
+    count: usize,
+}
+
+#[derive(Deserialize)]
+struct Puzzle {
+    name: String,
+    words: Vec<String>,
+    #[serde(default)]
+    constraints: Vec<ConstraintSpec>,
+    matches: Vec<String>,
+}
+
+/// A bundle of hacking scenarios loaded from the `.toml` fixtures below,
+/// modeled on the regex crate's `RegexTests` fixture harness: drop a new
+/// `.toml` file in `tests/fixtures/` and list it here to add a scenario.
+struct PuzzleTests {
+    puzzles: Vec<Puzzle>,
+}
+
+impl PuzzleTests {
+    fn load() -> Self {
+        let fixtures = [
+            include_str!("fixtures/single_constraint.toml"),
+            include_str!("fixtures/multiple_constraints.toml"),
+            include_str!("fixtures/contradiction.toml"),
+        ];
+
+        let puzzles = fixtures
+            .iter()
+            .map(|raw| toml::from_str(raw).expect("fixture should parse as a Puzzle"))
+            .collect();
+
+        PuzzleTests { puzzles }
+    }
+}
+
+#[test]
+fn filter_matches_expected_candidates() {
+    let tests = PuzzleTests::load();
+
+    for puzzle in &tests.puzzles {
+        let candidates: Vec<Guess> = puzzle.words.iter().cloned().map(Guess::new).collect();
+        let constraints: Vec<Guess> = puzzle
+            .constraints
+            .iter()
+            .map(|constraint| Guess::new_with_count(constraint.word.clone(), constraint.count))
+            .collect();
+
+        let mut got: Vec<&str> = filter(&candidates, &constraints)
+            .iter()
+            .map(|guess| guess.word.as_str())
+            .collect();
+        got.sort();
+
+        let mut expected: Vec<&str> = puzzle.matches.iter().map(String::as_str).collect();
+        expected.sort();
+
+        assert_eq!(got, expected, "puzzle \"{}\" did not match", puzzle.name);
+    }
+}
+
+#[derive(Deserialize)]
+struct RecommendationPuzzle {
+    name: String,
+    words: Vec<String>,
+    policy: String,
+    expected_top: String,
+}
+
+/// A bundle of recommender scenarios loaded the same way as `PuzzleTests`
+/// above, one fixture per `solver::Policy`, so each scoring strategy has
+/// its own regression coverage.
+struct RecommendationTests {
+    puzzles: Vec<RecommendationPuzzle>,
+}
+
+impl RecommendationTests {
+    fn load() -> Self {
+        let fixtures = [
+            include_str!("fixtures/recommend_minimax.toml"),
+            include_str!("fixtures/recommend_expected_remaining.toml"),
+            include_str!("fixtures/recommend_entropy.toml"),
+        ];
+
+        let puzzles = fixtures
+            .iter()
+            .map(|raw| toml::from_str(raw).expect("fixture should parse as a RecommendationPuzzle"))
+            .collect();
+
+        RecommendationTests { puzzles }
+    }
+}
+
+fn policy_from_name(name: &str) -> solver::Policy {
+    match name {
+        "minimax" => solver::Policy::Minimax,
+        "expected_remaining" => solver::Policy::ExpectedRemaining,
+        "entropy" => solver::Policy::Entropy,
+        other => panic!("unknown policy \"{}\" in fixture", other),
+    }
+}
+
+#[test]
+fn recommend_ranks_expected_guess_first() {
+    let tests = RecommendationTests::load();
+
+    for puzzle in &tests.puzzles {
+        let policy = policy_from_name(&puzzle.policy);
+        let candidates: Vec<Guess> = puzzle.words.iter().cloned().map(Guess::new).collect();
+        let refs: Vec<&Guess> = candidates.iter().collect();
+
+        let suggestions = solver::recommend(&refs, policy);
+        let top = &suggestions
+            .first()
+            .expect("recommend should suggest at least one guess")
+            .guess
+            .word;
+
+        assert_eq!(
+            top, &puzzle.expected_top,
+            "puzzle \"{}\" did not pick the expected top guess",
+            puzzle.name
+        );
+    }
+}